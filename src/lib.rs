@@ -1,77 +1,205 @@
 extern crate unicode_segmentation;
 extern crate unicode_width;
 
+use std::error;
+use std::fmt;
+
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
 
-/// `String` with Unicode width 1. The text equivalent of a pixel.
-#[derive(Clone)]
-struct Char(String);
+/// The error type for fallible `Stamp` construction and manipulation.
+///
+/// Each variant pinpoints where parsing or editing went wrong, mirroring
+/// how `std::string::FromUtf8Error` carries the offending bytes alongside
+/// the underlying `Utf8Error`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StampError {
+    /// The input, or the stamp an operation would produce, had no rows or
+    /// no columns.
+    Empty,
+    /// A row's display width didn't match the width of the first row.
+    RaggedRows {
+        expected: usize,
+        found: usize,
+        row: usize,
+    },
+    /// A grapheme cluster's display width isn't allowed where it appears:
+    /// stamp text takes widths 1 and 2, while a fill cell (`Stamp::filled`,
+    /// `pad`, `insert_row`, `insert_col`) takes width 1 only.
+    UnsupportedGraphemeWidth {
+        grapheme: String,
+        width: usize,
+        row: usize,
+        col: usize,
+    },
+    /// A position fell outside the bounds of the stamp: a `layer` offset, a
+    /// row or column index, or the origin of a `crop` region that extends
+    /// past the stamp's edge.
+    OutOfBounds {
+        col: usize,
+        row: usize,
+        width: usize,
+        height: usize,
+    },
+}
 
-impl Char {
-    pub fn new(s: &str) -> Result<Self, ()> {
-        let width = s.width();
+impl fmt::Display for StampError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            StampError::Empty => {
+                write!(f, "stamp must have at least one row and one column")
+            }
+            StampError::RaggedRows { expected, found, row } => {
+                write!(
+                    f,
+                    "row {} has width {}, expected {} (rows must be rectangular)",
+                    row, found, expected,
+                )
+            }
+            StampError::UnsupportedGraphemeWidth { ref grapheme, width, row, col } => {
+                write!(
+                    f,
+                    "grapheme {:?} at row {}, col {} has unsupported width {}",
+                    grapheme, row, col, width,
+                )
+            }
+            StampError::OutOfBounds { col, row, width, height } => {
+                write!(
+                    f,
+                    "position ({}, {}), or the region starting there, is out of bounds for a {}x{} stamp",
+                    col, row, width, height,
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for StampError {}
+
+/// One column-addressable cell of a `Stamp`.
+///
+/// A grapheme of display width 1 occupies a single `Narrow` cell. A
+/// grapheme of display width 2 (e.g. CJK ideographs, many emoji) occupies
+/// a `Wide` cell followed immediately by a `WideTail` marker, so that
+/// every column of a row has exactly one entry and column indexing into
+/// `Stamp::data` stays O(1).
+#[derive(Clone, Debug)]
+enum Cell {
+    Narrow(String),
+    Wide(String),
+    WideTail,
+}
 
-        if width != 1 {
-            return Err(());
+impl Cell {
+    fn new(grapheme: &str, row: usize, col: usize) -> Result<Vec<Self>, StampError> {
+        match grapheme.width() {
+            1 => Ok(vec![Cell::Narrow(grapheme.to_string())]),
+            2 => Ok(vec![Cell::Wide(grapheme.to_string()), Cell::WideTail]),
+            width => Err(StampError::UnsupportedGraphemeWidth {
+                grapheme: grapheme.to_string(),
+                width,
+                row,
+                col,
+            }),
         }
+    }
 
-        let c = Char(s.to_string());
+    fn blank() -> Self {
+        Cell::Narrow(" ".to_string())
+    }
+}
 
-        Ok(c)
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Cell::Narrow(ref s) | Cell::Wide(ref s) => write!(f, "{}", s),
+            Cell::WideTail => write!(f, ""),
+        }
     }
+}
 
-    pub fn to_string(&self) -> String {
-        self.0.clone()
+/// Blank any `Wide` cell not immediately followed by its `WideTail`, and
+/// any `WideTail` not immediately preceded by its `Wide` head, replacing
+/// each orphan with a single space. This repairs a row after a column-space
+/// write (see `Stamp::layer`) has split a double-width glyph in two.
+fn heal_orphaned_wide_cells(row: &mut [Cell]) {
+    let width = row.len();
+    let mut col = 0;
+
+    while col < width {
+        match row[col] {
+            Cell::Wide(_) => {
+                let has_tail = matches!(row.get(col + 1), Some(Cell::WideTail));
+                if !has_tail {
+                    row[col] = Cell::blank();
+                }
+                col += 1;
+            }
+            Cell::WideTail => {
+                let has_head = col > 0 && matches!(row[col - 1], Cell::Wide(_));
+                if !has_head {
+                    row[col] = Cell::blank();
+                }
+                col += 1;
+            }
+            Cell::Narrow(_) => {
+                col += 1;
+            }
+        }
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Stamp {
-    data: Vec<Vec<Char>>,  // Row-major
+    data: Vec<Vec<Cell>>,  // Row-major, one entry per display column
     height: usize,
     width: usize,
 }
 
 impl Stamp {
-    pub fn new(s: &str) -> Result<Self, ()> {
+    pub fn new(s: &str) -> Result<Self, StampError> {
         Self::from_rectangle(&to_rectangle(s)?)
     }
 
-    pub fn from_rectangle(s: &str) -> Result<Self, ()> {
+    pub fn from_rectangle(s: &str) -> Result<Self, StampError> {
         let rows: Vec<String> = s.split('\n').map(|s| s.to_string()).collect();
 
         let height = rows.len();
 
         // We must have at least one row.
         if height == 0 {
-            return Err(());
+            return Err(StampError::Empty);
         }
 
         let width = rows[0].width();
 
         // We must have at least one column.
         if width == 0 {
-            return Err(());
+            return Err(StampError::Empty);
         }
 
-        // Each row must have the same width.
-        if rows.iter().any(|s| s.width() != width) {
-            return Err(());
+        // Each row must have the same display width. This also catches a
+        // wide glyph straddling what would otherwise be the row boundary,
+        // since a dangling half-glyph can never sum to the expected width.
+        for (row, s) in rows.iter().enumerate() {
+            let found = s.width();
+            if found != width {
+                return Err(StampError::RaggedRows { expected: width, found, row });
+            }
         }
 
-        let mut data: Vec<Vec<Char>> = vec![];
+        let mut data: Vec<Vec<Cell>> = vec![];
 
-        for row in rows {
-            let mut chars: Vec<Char> = vec![];
+        for (row, s) in rows.iter().enumerate() {
+            let mut cells: Vec<Cell> = vec![];
 
-            for g in row.graphemes(true) {
-                let c = Char::new(g)?;
-                chars.push(c);
+            for g in s.graphemes(true) {
+                let col = cells.len();
+                cells.extend(Cell::new(g, row, col)?);
             }
 
-            data.push(chars);
+            data.push(cells);
         }
 
         Ok(Stamp {
@@ -92,8 +220,8 @@ impl Stamp {
     pub fn rows(&self) -> Vec<String> {
         self.data
             .iter()
-            .map(|chars| {
-                let strings: Vec<String> = chars
+            .map(|cells| {
+                let strings: Vec<String> = cells
                     .iter()
                     .map(|c| c.to_string())
                     .collect();
@@ -106,9 +234,54 @@ impl Stamp {
         self.rows().join("\n")
     }
 
-    pub fn layer(&self, other: &Stamp, col: usize, row: usize) -> Result<Stamp, ()> {
+    /// Overlay `other` onto `self` at column `col`, row `row`, copying every
+    /// cell of `other` (clipped to `self`'s bounds).
+    ///
+    /// Because cells are column-addressable, a write can land in the middle
+    /// of a double-width glyph on either stamp. Any half-glyph left dangling
+    /// by the write — on the edge of `self` that got overwritten, or on the
+    /// edge of `other` that got clipped — is blanked to a space rather than
+    /// rendered as a corrupt half-glyph.
+    pub fn layer(&self, other: &Stamp, col: usize, row: usize) -> Result<Stamp, StampError> {
+        self.layer_cells(other, col, row, None)
+    }
+
+    /// Like [`Stamp::layer`], but cells of `other` whose grapheme is
+    /// `transparent` are skipped instead of copied, leaving whatever was
+    /// already at that position in `self` showing through.
+    ///
+    /// This is what turns `Stamp` into a sprite compositor: a
+    /// non-rectangular sprite drawn on a `transparent` background (e.g.
+    /// `" "` or `"."`) can be overlaid without blanking out what's beneath
+    /// it.
+    pub fn layer_transparent(
+        &self,
+        other: &Stamp,
+        col: usize,
+        row: usize,
+        transparent: &str,
+    ) -> Result<Stamp, StampError> {
+        self.layer_cells(other, col, row, Some(transparent))
+    }
+
+    /// Shared implementation of [`Stamp::layer`] and
+    /// [`Stamp::layer_transparent`]. `transparent`, when present, names the
+    /// grapheme of `other` to skip rather than copy; `None` copies every
+    /// cell unconditionally (the opaque case).
+    fn layer_cells(
+        &self,
+        other: &Stamp,
+        col: usize,
+        row: usize,
+        transparent: Option<&str>,
+    ) -> Result<Stamp, StampError> {
         if self.width() <= col || self.height() <= row {
-            return Err(());
+            return Err(StampError::OutOfBounds {
+                col,
+                row,
+                width: self.width(),
+                height: self.height(),
+            });
         }
 
         let mut stamp = self.clone();
@@ -117,18 +290,308 @@ impl Stamp {
         let max_row_index = std::cmp::min(row + other.height(), self.height());
 
         for r in row..max_row_index {
+            // Tracks whether the `Wide` half of a transparent double-width
+            // glyph was skipped, so its `WideTail` is skipped along with it
+            // rather than copied on its own.
+            let mut skip_tail = false;
+
             for c in col..max_col_index {
-                stamp.data[r][c] = other.data[r - row][c - col].clone();
+                let cell = &other.data[r - row][c - col];
+
+                let skip = match (cell, transparent) {
+                    (Cell::WideTail, _) => skip_tail,
+                    (_, Some(key)) => cell.to_string() == key,
+                    (_, None) => false,
+                };
+
+                skip_tail = matches!(cell, Cell::Wide(_)) && skip;
+
+                if !skip {
+                    stamp.data[r][c] = cell.clone();
+                }
             }
+
+            heal_orphaned_wide_cells(&mut stamp.data[r]);
         }
 
         Ok(stamp)
     }
+
+    /// Build a `Stamp` from arbitrary text, never failing.
+    ///
+    /// Mirrors `String::from_utf8_lossy`: any grapheme whose display width
+    /// isn't 1 or 2 is replaced by the default placeholder (U+FFFD `�`),
+    /// and ragged rows are right-padded with spaces. Useful for ingesting
+    /// arbitrary text or terminal dumps that `Stamp::new` would otherwise
+    /// reject outright.
+    pub fn from_str_lossy(s: &str) -> Stamp {
+        Self::from_str_lossy_with_placeholder(s, "\u{FFFD}")
+    }
+
+    /// Like [`Stamp::from_str_lossy`], but with a caller-chosen placeholder
+    /// grapheme instead of U+FFFD.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `placeholder` is not Unicode width 1.
+    pub fn from_str_lossy_with_placeholder(s: &str, placeholder: &str) -> Stamp {
+        assert_eq!(
+            placeholder.width(), 1,
+            "placeholder must have Unicode width 1, got {:?}", placeholder,
+        );
+
+        if s.is_empty() {
+            return Stamp::from_rectangle(placeholder)
+                .expect("a lone unit-width placeholder is a valid 1x1 stamp");
+        }
+
+        let rows: Vec<String> = s
+            .split('\n')
+            .map(|line| {
+                let mut row = String::new();
+
+                for g in line.graphemes(true) {
+                    match g.width() {
+                        1 | 2 => row += g,
+                        width => {
+                            // Zero-width/control graphemes and anything
+                            // wider than a double-width glyph are replaced
+                            // by as many placeholder cells as the original
+                            // grapheme's width, so row widths stay in
+                            // units of whole cells. A zero-width grapheme
+                            // becomes exactly one placeholder cell.
+                            for _ in 0..std::cmp::max(width, 1) {
+                                row += placeholder;
+                            }
+                        }
+                    }
+                }
+
+                // An empty line gets a single placeholder cell, so every
+                // row has at least one cell and input made only of empty
+                // lines still yields a non-empty stamp.
+                if row.is_empty() {
+                    row += placeholder;
+                }
+
+                row
+            })
+            .collect();
+
+        let rectangle = to_rectangle(&rows.join("\n"))
+            .expect("at least one non-empty row was guaranteed above");
+
+        Stamp::from_rectangle(&rectangle)
+            .expect("every cell above is unit- or double-width and rows are now rectangular")
+    }
+
+    /// Build a solid `width` by `height` rectangle filled with `cell`.
+    ///
+    /// `cell` must be a single grapheme of Unicode width 1. Combined with
+    /// `layer`, this is the easiest way to build a background or a border.
+    pub fn filled(cell: &str, width: usize, height: usize) -> Result<Stamp, StampError> {
+        if width == 0 || height == 0 {
+            return Err(StampError::Empty);
+        }
+
+        let parsed = Cell::new(cell, 0, 0)?;
+
+        let narrow = match parsed.as_slice() {
+            [c @ Cell::Narrow(_)] => c.clone(),
+            _ => return Err(StampError::UnsupportedGraphemeWidth {
+                grapheme: cell.to_string(),
+                width: cell.width(),
+                row: 0,
+                col: 0,
+            }),
+        };
+
+        let data = vec![vec![narrow; width]; height];
+
+        Ok(Stamp { data, width, height })
+    }
+
+    /// Repeat this stamp in a `cols` by `rows` grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cols` or `rows` is zero.
+    pub fn tile(&self, cols: usize, rows: usize) -> Stamp {
+        assert!(
+            cols > 0 && rows > 0,
+            "tile dimensions must be nonzero, got {}x{}", cols, rows,
+        );
+
+        let mut data: Vec<Vec<Cell>> = Vec::with_capacity(self.height * rows);
+
+        for _ in 0..rows {
+            for base_row in &self.data {
+                let mut row: Vec<Cell> = Vec::with_capacity(self.width * cols);
+
+                for _ in 0..cols {
+                    row.extend(base_row.iter().cloned());
+                }
+
+                data.push(row);
+            }
+        }
+
+        Stamp {
+            data,
+            width: self.width * cols,
+            height: self.height * rows,
+        }
+    }
+
+    /// Extract the `width` by `height` subregion starting at column `col`,
+    /// row `row`.
+    ///
+    /// If the region's edge splits a double-width glyph, the orphaned half
+    /// is blanked to a space, the same way [`Stamp::layer`] handles a write
+    /// that splits one.
+    pub fn crop(&self, col: usize, row: usize, width: usize, height: usize) -> Result<Stamp, StampError> {
+        if width == 0 || height == 0 {
+            return Err(StampError::Empty);
+        }
+
+        if col + width > self.width || row + height > self.height {
+            return Err(StampError::OutOfBounds {
+                col,
+                row,
+                width: self.width,
+                height: self.height,
+            });
+        }
+
+        let data: Vec<Vec<Cell>> = self.data[row..row + height]
+            .iter()
+            .map(|cells| {
+                let mut cropped: Vec<Cell> = cells[col..col + width].to_vec();
+                heal_orphaned_wide_cells(&mut cropped);
+                cropped
+            })
+            .collect();
+
+        Ok(Stamp { data, width, height })
+    }
+
+    /// Grow this stamp by `top`/`right`/`bottom`/`left` cells of `fill`.
+    ///
+    /// `fill` must be a single grapheme of Unicode width 1. Built from
+    /// [`Stamp::filled`] and [`Stamp::layer`]: a filled canvas of the new
+    /// size with `self` layered into the middle.
+    pub fn pad(
+        &self,
+        top: usize,
+        right: usize,
+        bottom: usize,
+        left: usize,
+        fill: &str,
+    ) -> Result<Stamp, StampError> {
+        let canvas = Stamp::filled(fill, self.width + left + right, self.height + top + bottom)?;
+
+        canvas.layer(self, left, top)
+    }
+
+    /// Insert a row of `fill` cells at `index`, shifting subsequent rows
+    /// down. `index` may equal `self.height()` to append.
+    pub fn insert_row(&self, index: usize, fill: &str) -> Result<Stamp, StampError> {
+        if index > self.height {
+            return Err(StampError::OutOfBounds {
+                col: 0,
+                row: index,
+                width: self.width,
+                height: self.height,
+            });
+        }
+
+        let new_row = Stamp::filled(fill, self.width, 1)?
+            .data
+            .into_iter()
+            .next()
+            .expect("a 1-row stamp has exactly one row");
+
+        let mut data = self.data.clone();
+        data.insert(index, new_row);
+
+        Ok(Stamp { data, width: self.width, height: self.height + 1 })
+    }
+
+    /// Insert a column of `fill` cells at `index`, shifting subsequent
+    /// columns right. `index` may equal `self.width()` to append.
+    pub fn insert_col(&self, index: usize, fill: &str) -> Result<Stamp, StampError> {
+        if index > self.width {
+            return Err(StampError::OutOfBounds {
+                col: index,
+                row: 0,
+                width: self.width,
+                height: self.height,
+            });
+        }
+
+        let new_col = Stamp::filled(fill, 1, self.height)?;
+
+        let mut data = self.data.clone();
+        for (r, row) in data.iter_mut().enumerate() {
+            row.insert(index, new_col.data[r][0].clone());
+            heal_orphaned_wide_cells(row);
+        }
+
+        Ok(Stamp { data, width: self.width + 1, height: self.height })
+    }
+
+    /// Remove the row at `index`, shifting subsequent rows up.
+    pub fn remove_row(&self, index: usize) -> Result<Stamp, StampError> {
+        if index >= self.height {
+            return Err(StampError::OutOfBounds {
+                col: 0,
+                row: index,
+                width: self.width,
+                height: self.height,
+            });
+        }
+
+        if self.height == 1 {
+            return Err(StampError::Empty);
+        }
+
+        let mut data = self.data.clone();
+        data.remove(index);
+
+        Ok(Stamp { data, width: self.width, height: self.height - 1 })
+    }
+
+    /// Remove the column at `index`, shifting subsequent columns left.
+    ///
+    /// If `index` falls inside a double-width glyph, the glyph's other half
+    /// is blanked to a space rather than left dangling.
+    pub fn remove_col(&self, index: usize) -> Result<Stamp, StampError> {
+        if index >= self.width {
+            return Err(StampError::OutOfBounds {
+                col: index,
+                row: 0,
+                width: self.width,
+                height: self.height,
+            });
+        }
+
+        if self.width == 1 {
+            return Err(StampError::Empty);
+        }
+
+        let mut data = self.data.clone();
+        for row in data.iter_mut() {
+            row.remove(index);
+            heal_orphaned_wide_cells(row);
+        }
+
+        Ok(Stamp { data, width: self.width - 1, height: self.height })
+    }
 }
 
-fn to_rectangle(s: &str) -> Result<String, ()> {
+fn to_rectangle(s: &str) -> Result<String, StampError> {
     if s.is_empty() {
-        return Err(());
+        return Err(StampError::Empty);
     }
 
     let rows: Vec<String> = s.split('\n').map(|s| s.to_string()).collect();
@@ -159,7 +622,7 @@ fn to_rectangle(s: &str) -> Result<String, ()> {
 
 #[cfg(test)]
 mod tests {
-    use super::{Stamp, to_rectangle};
+    use super::{Stamp, StampError, to_rectangle};
 
     const VALID_STAMPS_LEN: usize = 12;
     type ValidStamps = [&'static str; VALID_STAMPS_LEN];
@@ -274,4 +737,299 @@ mod tests {
         assert_eq!(to_rectangle("\na\nbc").ok().unwrap(), "  \na \nbc");
         assert_eq!(to_rectangle("aaa\nb\ncc").ok().unwrap(), "aaa\nb  \ncc ");
     }
+
+    #[test]
+    fn test_error_messages() {
+        assert_eq!(
+            Stamp::new("").unwrap_err(),
+            StampError::Empty,
+        );
+
+        let err = Stamp::from_rectangle("ab\ncde").unwrap_err();
+        assert_eq!(
+            err,
+            StampError::RaggedRows { expected: 2, found: 3, row: 1 },
+        );
+        assert_eq!(
+            err.to_string(),
+            "row 1 has width 3, expected 2 (rows must be rectangular)",
+        );
+
+        let err = Stamp::from_rectangle("中\u{200b}x").unwrap_err();
+        assert_eq!(
+            err,
+            StampError::UnsupportedGraphemeWidth {
+                grapheme: "\u{200b}".to_string(),
+                width: 0,
+                row: 0,
+                col: 2,
+            },
+        );
+
+        let s1 = Stamp::from_rectangle("oo\noo").ok().unwrap();
+        let s2 = Stamp::from_rectangle("x").ok().unwrap();
+        assert_eq!(
+            s1.layer(&s2, 2, 0).unwrap_err(),
+            StampError::OutOfBounds { col: 2, row: 0, width: 2, height: 2 },
+        );
+    }
+
+    #[test]
+    fn test_from_str_lossy_passes_through_valid_input() {
+        for s in &VALID_STAMPS {
+            assert_eq!(Stamp::from_str_lossy(s).render(), *s);
+        }
+    }
+
+    #[test]
+    fn test_from_str_lossy_empty() {
+        assert_eq!(Stamp::from_str_lossy("").render(), "\u{FFFD}");
+    }
+
+    #[test]
+    fn test_from_str_lossy_ragged_rows_are_padded() {
+        assert_eq!(Stamp::from_str_lossy("a\nbc").render(), "a \nbc");
+    }
+
+    #[test]
+    fn test_from_str_lossy_replaces_zero_width_graphemes_with_one_cell() {
+        assert_eq!(Stamp::from_str_lossy("a\u{200b}b").render(), "a\u{FFFD}b");
+    }
+
+    #[test]
+    fn test_from_str_lossy_preserves_wide_graphemes() {
+        assert_eq!(Stamp::from_str_lossy("中").render(), "中");
+    }
+
+    #[test]
+    fn test_from_str_lossy_blank_line_becomes_placeholder() {
+        assert_eq!(Stamp::from_str_lossy("a\n\u{200b}").render(), "a\n\u{FFFD}");
+    }
+
+    #[test]
+    fn test_from_str_lossy_with_custom_placeholder() {
+        assert_eq!(Stamp::from_str_lossy_with_placeholder("\u{200b}", "?").render(), "?");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_str_lossy_with_placeholder_panics_on_non_unit_width() {
+        Stamp::from_str_lossy_with_placeholder("a", "中");
+    }
+
+    #[test]
+    fn test_wide_graphemes_render_and_report_width() {
+        let st = Stamp::from_rectangle("中文\noooo").ok().unwrap();
+        assert_eq!(st.width(), 4);
+        assert_eq!(st.height(), 2);
+        assert_eq!(st.render(), "中文\noooo");
+    }
+
+    #[test]
+    fn test_layer_narrow_over_wide_blanks_orphaned_half() {
+        // Laying a narrow glyph over the left half of a wide glyph must
+        // blank the right half too, or it would render as a corrupt
+        // dangling continuation cell.
+        let base = Stamp::from_rectangle("中oo").ok().unwrap();
+        let patch = Stamp::from_rectangle("x").ok().unwrap();
+
+        let out = base.layer(&patch, 0, 0).ok().unwrap().render();
+        assert_eq!(out, "x oo");
+    }
+
+    #[test]
+    fn test_layer_wide_over_narrow_blanks_clipped_half() {
+        // Laying a wide glyph where only its left half fits must blank
+        // that half rather than render an orphaned `Wide` with no tail.
+        let base = Stamp::from_rectangle("oooo").ok().unwrap();
+        let patch = Stamp::from_rectangle("中").ok().unwrap();
+
+        let out = base.layer(&patch, 3, 0).ok().unwrap().render();
+        assert_eq!(out, "ooo ");
+    }
+
+    #[test]
+    fn test_layer_wide_over_wide_aligned() {
+        let base = Stamp::from_rectangle("中文").ok().unwrap();
+        let patch = Stamp::from_rectangle("文中").ok().unwrap();
+
+        let out = base.layer(&patch, 0, 0).ok().unwrap().render();
+        assert_eq!(out, "文中");
+    }
+
+    #[test]
+    fn test_layer_transparent_space_key() {
+        let base = Stamp::from_rectangle("oooooo\noooooo\noooooo").ok().unwrap();
+        let sprite = Stamp::from_rectangle(" x \nxxx\n x ").ok().unwrap();
+
+        let out = base.layer_transparent(&sprite, 1, 0, " ").ok().unwrap().render();
+        assert_eq!(out, "ooxooo\noxxxoo\nooxooo");
+    }
+
+    #[test]
+    fn test_layer_transparent_dot_key() {
+        let base = Stamp::from_rectangle("abcd\nefgh").ok().unwrap();
+        let sprite = Stamp::from_rectangle(".X\nX.").ok().unwrap();
+
+        let out = base.layer_transparent(&sprite, 1, 0, ".").ok().unwrap().render();
+        assert_eq!(out, "abXd\neXgh");
+    }
+
+    #[test]
+    fn test_layer_transparent_clipped_at_edge() {
+        let base = Stamp::from_rectangle("ooo\nooo").ok().unwrap();
+        let sprite = Stamp::from_rectangle(".XX\nXX.").ok().unwrap();
+
+        let out = base.layer_transparent(&sprite, 1, 0, ".").ok().unwrap().render();
+        assert_eq!(out, "ooX\noXX");
+    }
+
+    #[test]
+    fn test_layer_transparent_leaves_untouched_cells_alone() {
+        // An entirely-transparent overlay should change nothing.
+        let base = Stamp::from_rectangle("abc\ndef").ok().unwrap();
+        let sprite = Stamp::from_rectangle("...\n...").ok().unwrap();
+
+        let out = base.layer_transparent(&sprite, 0, 0, ".").ok().unwrap().render();
+        assert_eq!(out, "abc\ndef");
+    }
+
+    #[test]
+    fn test_filled() {
+        let st = Stamp::filled("x", 3, 2).ok().unwrap();
+        assert_eq!(st.width(), 3);
+        assert_eq!(st.height(), 2);
+        assert_eq!(st.render(), "xxx\nxxx");
+    }
+
+    #[test]
+    fn test_filled_rejects_non_unit_width_cell() {
+        assert!(Stamp::filled("中", 2, 2).is_err());
+        assert!(Stamp::filled("", 2, 2).is_err());
+    }
+
+    #[test]
+    fn test_filled_rejects_zero_dimensions() {
+        assert_eq!(Stamp::filled("x", 0, 2).unwrap_err(), StampError::Empty);
+        assert_eq!(Stamp::filled("x", 2, 0).unwrap_err(), StampError::Empty);
+    }
+
+    #[test]
+    fn test_tile() {
+        let st = Stamp::from_rectangle("ab\ncd").ok().unwrap();
+
+        let out = st.tile(2, 3).render();
+        assert_eq!(out, "abab\ncdcd\nabab\ncdcd\nabab\ncdcd");
+    }
+
+    #[test]
+    fn test_tile_preserves_wide_graphemes() {
+        let st = Stamp::from_rectangle("中").ok().unwrap();
+
+        let out = st.tile(3, 1).render();
+        assert_eq!(out, "中中中");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_tile_panics_on_zero_dimension() {
+        let st = Stamp::from_rectangle("a").ok().unwrap();
+        st.tile(0, 1);
+    }
+
+    #[test]
+    fn test_crop() {
+        let st = Stamp::from_rectangle("abcd\nefgh\nijkl").ok().unwrap();
+
+        let out = st.crop(1, 1, 2, 2).ok().unwrap().render();
+        assert_eq!(out, "fg\njk");
+    }
+
+    #[test]
+    fn test_crop_out_of_bounds() {
+        let st = Stamp::from_rectangle("abcd\nefgh").ok().unwrap();
+        assert_eq!(
+            st.crop(3, 0, 2, 1).unwrap_err(),
+            StampError::OutOfBounds { col: 3, row: 0, width: 4, height: 2 },
+        );
+    }
+
+    #[test]
+    fn test_crop_blanks_split_wide_glyph() {
+        let st = Stamp::from_rectangle("中文ab").ok().unwrap();
+
+        let cropped = st.crop(1, 0, 3, 1).ok().unwrap();
+        assert_eq!(cropped.width(), 3);
+        assert_eq!(cropped.render(), " 文");
+    }
+
+    #[test]
+    fn test_pad() {
+        let st = Stamp::from_rectangle("ab\ncd").ok().unwrap();
+
+        let out = st.pad(1, 1, 1, 1, ".").ok().unwrap().render();
+        assert_eq!(out, "....\n.ab.\n.cd.\n....");
+    }
+
+    #[test]
+    fn test_insert_row() {
+        let st = Stamp::from_rectangle("ab\ncd").ok().unwrap();
+
+        assert_eq!(st.insert_row(0, ".").ok().unwrap().render(), "..\nab\ncd");
+        assert_eq!(st.insert_row(1, ".").ok().unwrap().render(), "ab\n..\ncd");
+        assert_eq!(st.insert_row(2, ".").ok().unwrap().render(), "ab\ncd\n..");
+        assert!(st.insert_row(3, ".").is_err());
+    }
+
+    #[test]
+    fn test_insert_col() {
+        let st = Stamp::from_rectangle("ab\ncd").ok().unwrap();
+
+        assert_eq!(st.insert_col(0, ".").ok().unwrap().render(), ".ab\n.cd");
+        assert_eq!(st.insert_col(1, ".").ok().unwrap().render(), "a.b\nc.d");
+        assert_eq!(st.insert_col(2, ".").ok().unwrap().render(), "ab.\ncd.");
+        assert!(st.insert_col(3, ".").is_err());
+    }
+
+    #[test]
+    fn test_insert_col_blanks_split_wide_glyph() {
+        let st = Stamp::from_rectangle("中a").ok().unwrap();
+
+        // Inserting at index 1 lands between the `Wide` head and its
+        // `WideTail`, orphaning both; they must be blanked rather than
+        // left dangling.
+        let out = st.insert_col(1, ".").ok().unwrap();
+        assert_eq!(out.width(), 4);
+        assert_eq!(out.render(), " . a");
+    }
+
+    #[test]
+    fn test_remove_row() {
+        let st = Stamp::from_rectangle("ab\ncd\nef").ok().unwrap();
+
+        assert_eq!(st.remove_row(1).ok().unwrap().render(), "ab\nef");
+        assert!(st.remove_row(3).is_err());
+
+        let single = Stamp::from_rectangle("ab").ok().unwrap();
+        assert_eq!(single.remove_row(0).unwrap_err(), StampError::Empty);
+    }
+
+    #[test]
+    fn test_remove_col() {
+        let st = Stamp::from_rectangle("abc\ndef").ok().unwrap();
+
+        assert_eq!(st.remove_col(1).ok().unwrap().render(), "ac\ndf");
+        assert!(st.remove_col(3).is_err());
+
+        let single = Stamp::from_rectangle("a\nb").ok().unwrap();
+        assert_eq!(single.remove_col(0).unwrap_err(), StampError::Empty);
+    }
+
+    #[test]
+    fn test_remove_col_blanks_orphaned_wide_half() {
+        let st = Stamp::from_rectangle("中a").ok().unwrap();
+
+        let out = st.remove_col(0).ok().unwrap().render();
+        assert_eq!(out, " a");
+    }
 }